@@ -273,8 +273,9 @@ impl Mixture {
     /// which generates `n` samples.
     pub fn add(&self, n: usize, src: NormalData) -> Mixture {
 
-        if self.normal.len() > 0 && self.normal[0].1.len() != src.len() {
-            panic!("Invalid length.");
+        if self.normal.len() > 0 {
+            safety_dim_check!(self.normal[0].1.len() == src.len(), "Invalid length.",
+                "Mixture::add: sources have {} dimensions, src has {}", self.normal[0].1.len(), src.len());
         }
 
         let mut v = self.normal.clone();
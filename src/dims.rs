@@ -0,0 +1,67 @@
+//! Macros for checking the dimensions of vectors and matrices.
+//!
+//! By default a failed dimension check panics with a short message. The
+//! behaviour can be changed with the following cargo features:
+//!
+//! * `strict_dims`: the panic message contains the name of the operation and the
+//!   shapes of all operands involved, e.g. `d_gemv: A is 128x64, x has len 63, y has len 128`.
+//! * `unchecked_dims`: in builds without debug assertions (i.e. release builds)
+//!   all checks done via `dim_check!` are compiled out. Checks done via
+//!   `safety_dim_check!` are always performed.
+//!
+//! `safety_dim_check!` is used where a mismatch would lead to undefined behaviour
+//! or to an inconsistent data structure, e.g. in front of calls into BLAS or when
+//! rows are added to a matrix. `dim_check!` is used where a mismatch only leads to
+//! a wrong result or a panic on an out of bounds access.
+//!
+//! Checks which are not about the dimensions of operands (e.g. whether a
+//! layer exists or a network has at least two layers) are not affected
+//! by these features. The same holds for the check of the exported `mat!`
+//! macro which cannot use these crate internal macros.
+
+/// Panics with a detailed message if `strict_dims` is enabled.
+#[cfg(feature = "strict_dims")]
+macro_rules! dim_fail {
+    ($short:expr, $($arg:tt)+) => ( panic!($($arg)+) )
+}
+
+/// Panics with the short message if `strict_dims` is not enabled.
+#[cfg(not(feature = "strict_dims"))]
+macro_rules! dim_fail {
+    ($short:expr, $($arg:tt)+) => ( panic!($short) )
+}
+
+/// Checks a dimension constraint which is required for memory safety or to keep
+/// a data structure consistent.
+///
+/// The check is always performed. The first argument is the condition, the second
+/// argument the short message and the remaining arguments the format string and
+/// its arguments used for the detailed message.
+macro_rules! safety_dim_check {
+    ($cond:expr, $short:expr, $($arg:tt)+) => (
+        if !$cond {
+            dim_fail!($short, $($arg)+);
+        }
+    )
+}
+
+/// Checks a dimension constraint.
+///
+/// Same as `safety_dim_check!` but compiled out if `unchecked_dims` is enabled
+/// and debug assertions are disabled.
+#[cfg(not(all(feature = "unchecked_dims", not(debug_assertions))))]
+macro_rules! dim_check {
+    ($cond:expr, $short:expr, $($arg:tt)+) => ( safety_dim_check!($cond, $short, $($arg)+) )
+}
+
+/// Checks a dimension constraint (compiled out).
+///
+/// The condition is still type checked but never evaluated.
+#[cfg(all(feature = "unchecked_dims", not(debug_assertions)))]
+macro_rules! dim_check {
+    ($cond:expr, $short:expr, $($arg:tt)+) => (
+        if false {
+            let _ = $cond;
+        }
+    )
+}
@@ -1,7 +1,42 @@
-//! Collection of some common data structures.
+//! Collection of some common data structures and basic computational geometry.
+//!
+//! Functions which operate on a set of points expect the points to be stored
+//! in the rows of a matrix. For two dimensional points the first column
+//! contains the `x` coordinates and the second column the `y` coordinates.
+//!
+//! # Example
+//!
+//! ```
+//! # #[macro_use] extern crate rustml;
+//! use rustml::*;
+//! use rustml::geometry::*;
+//!
+//! # fn main() {
+//! let m = mat![
+//!     0.0, 0.0;
+//!     2.0, 0.0;
+//!     1.0, 1.0;  // inside of the hull
+//!     2.0, 2.0;
+//!     0.0, 2.0
+//! ];
+//! assert_eq!(convex_hull(&m), vec![0, 1, 3, 4]);
+//!
+//! // polygon with the vertices of the hull
+//! let hull = Matrix::from_row_vectors(
+//!     &m.row_iter_of(&convex_hull(&m)).map(|r| r.to_vec()).collect::<Vec<_>>()
+//! );
+//! assert!(point_in_polygon(&Point2D::new(0.5, 1.5), &hull));
+//! assert!(!point_in_polygon(&Point2D::new(2.5, 1.5), &hull));
+//! # }
+//! ```
+extern crate num;
+
 use std::fmt;
+use self::num::traits::{Num, Signed};
+use matrix::Matrix;
 
 /// A point with two dimensions, `x` and `y`.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Point2D<T> {
     /// The first dimension of the point.
     pub x: T,
@@ -26,8 +61,193 @@ impl <T: fmt::Display + Clone> fmt::Display for Point2D<T> {
     }
 }
 
+// ----------------------------------------------------------------------------
+
+/// Axis aligned bounding box of a set of points with an arbitrary number of
+/// dimensions.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoundingBox<T> {
+    /// The smallest value of each dimension.
+    pub min: Vec<T>,
+    /// The largest value of each dimension.
+    pub max: Vec<T>
+}
+
+impl <T: PartialOrd> BoundingBox<T> {
+    /// Returns the number of dimensions of the bounding box.
+    pub fn dims(&self) -> usize { self.min.len() }
+
+    /// Returns `true` if the point `p` is inside of the bounding box or
+    /// on its boundary.
+    ///
+    /// Panics if the dimension of the point does not match the dimension
+    /// of the bounding box.
+    pub fn contains(&self, p: &[T]) -> bool {
+
+        dim_check!(p.len() == self.dims(), "Dimensions do not match.",
+            "contains: bounding box has {} dimensions, point has len {}", self.dims(), p.len());
+        p.iter().zip(self.min.iter().zip(self.max.iter())).all(|(x, (a, b))| x >= a && x <= b)
+    }
+}
+
+/// Computes the bounding box of the points in the rows of the matrix.
+///
+/// Rows which contain NaN are ignored. Returns `None` if the matrix has no rows
+/// or if all rows contain NaN.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate rustml;
+/// use rustml::*;
+/// use rustml::geometry::*;
+///
+/// # fn main() {
+/// let m = mat![1, 5; 3, 2; -1, 4];
+/// let b = bounding_box(&m).unwrap();
+/// assert_eq!(b.min, vec![-1, 2]);
+/// assert_eq!(b.max, vec![3, 5]);
+/// # }
+/// ```
+pub fn bounding_box<T: PartialOrd + Copy>(points: &Matrix<T>) -> Option<BoundingBox<T>> {
+
+    let mut rows = points.row_iter().filter(|r| !has_nan(r));
+    let first = rows.next()?;
+
+    let mut min = first.to_vec();
+    let mut max = first.to_vec();
+    for r in rows {
+        for (i, &x) in r.iter().enumerate() {
+            if x < min[i] { min[i] = x; }
+            if x > max[i] { max[i] = x; }
+        }
+    }
+    Some(BoundingBox {
+        min: min,
+        max: max
+    })
+}
+
+/// Returns `true` if the row contains NaN, i.e. a value which cannot be
+/// compared with itself.
+fn has_nan<T: PartialOrd>(r: &[T]) -> bool {
+    r.iter().any(|x| x.partial_cmp(x).is_none())
+}
+
+/// Computes the cross product of the vectors `o -> a` and `o -> b`.
+///
+/// The result is positive if `o`, `a`, `b` make a counter-clockwise turn,
+/// negative for a clockwise turn and zero if the points are collinear.
+fn cross<T: Num + Signed + Copy>(o: &[T], a: &[T], b: &[T]) -> T {
+    (a[0] - o[0]) * (b[1] - o[1]) - (a[1] - o[1]) * (b[0] - o[0])
+}
+
+/// Computes the convex hull of the two dimensional points in the rows of the
+/// matrix via Andrew's monotone chain algorithm in O(n log n).
+///
+/// Returns the indexes of the rows which are the vertices of the hull in
+/// counter-clockwise order starting with the point with the smallest `x` coordinate
+/// (and the smallest `y` coordinate if there is more than one such point).
+/// Points on an edge of the hull are not vertices. If a point occurs several
+/// times only the index of its first occurrence is returned. Rows which contain
+/// NaN are ignored. If the matrix has no rows an empty vector is returned.
+///
+/// Panics if the matrix has rows but does not have two columns.
+pub fn convex_hull<T: Num + Signed + PartialOrd + Copy>(points: &Matrix<T>) -> Vec<usize> {
+
+    dim_check!(points.rows() == 0 || points.cols() == 2, "Invalid dimensions.",
+        "convex_hull: points is {}x{}, expected 2 columns", points.rows(), points.cols());
+
+    let p = |i: usize| points.row(i).unwrap();
+
+    let mut idx = (0..points.rows()).filter(|&i| !has_nan(p(i))).collect::<Vec<usize>>();
+    // the sort is stable, i.e. duplicates keep the order of their occurrence;
+    // without NaN all points are comparable
+    idx.sort_by(|&i, &j| p(i).partial_cmp(p(j)).unwrap());
+    idx.dedup_by(|i, j| p(*i) == p(*j));
+
+    if idx.len() < 3 {
+        return idx;
+    }
+
+    let mut lower: Vec<usize> = vec![];
+    for &i in &idx {
+        while lower.len() >= 2 &&
+            cross(p(lower[lower.len() - 2]), p(lower[lower.len() - 1]), p(i)) <= T::zero() {
+            lower.pop();
+        }
+        lower.push(i);
+    }
+
+    let mut upper: Vec<usize> = vec![];
+    for &i in idx.iter().rev() {
+        while upper.len() >= 2 &&
+            cross(p(upper[upper.len() - 2]), p(upper[upper.len() - 1]), p(i)) <= T::zero() {
+            upper.pop();
+        }
+        upper.push(i);
+    }
+
+    // the last point of each chain is the first point of the other chain
+    lower.pop();
+    upper.pop();
+    lower.append(&mut upper);
+    lower
+}
+
+/// Returns `true` if the point `p` is inside of the polygon or on its boundary.
+///
+/// The vertices of the polygon are stored in the rows of the matrix `polygon`
+/// in clockwise or counter-clockwise order. The polygon is closed automatically,
+/// i.e. the last vertex is connected with the first one. The polygon does not
+/// need to be convex. The test is done via the winding number of the polygon
+/// around the point. If the matrix has no rows `false` is returned.
+///
+/// Panics if the matrix has rows but does not have two columns.
+pub fn point_in_polygon<T: Num + Signed + PartialOrd + Copy>(p: &Point2D<T>, polygon: &Matrix<T>) -> bool {
+
+    dim_check!(polygon.rows() == 0 || polygon.cols() == 2, "Invalid dimensions.",
+        "point_in_polygon: polygon is {}x{}, expected 2 columns", polygon.rows(), polygon.cols());
+
+    let n = polygon.rows();
+    let q = [p.x, p.y];
+    let mut winding = 0;
+
+    for i in 0..n {
+        let a = polygon.row(i).unwrap();
+        let b = polygon.row((i + 1) % n).unwrap();
+        let c = cross(a, b, &q);
+
+        // point is on the edge from a to b
+        if c == T::zero() &&
+            q[0] >= min(a[0], b[0]) && q[0] <= max(a[0], b[0]) &&
+            q[1] >= min(a[1], b[1]) && q[1] <= max(a[1], b[1]) {
+            return true;
+        }
+
+        if a[1] <= q[1] {
+            // upward crossing with the point left of the edge
+            if b[1] > q[1] && c > T::zero() {
+                winding += 1;
+            }
+        } else {
+            // downward crossing with the point right of the edge
+            if b[1] <= q[1] && c < T::zero() {
+                winding -= 1;
+            }
+        }
+    }
+    winding != 0
+}
+
+fn min<T: PartialOrd>(a: T, b: T) -> T { if a < b { a } else { b } }
+
+fn max<T: PartialOrd>(a: T, b: T) -> T { if a > b { a } else { b } }
+
 #[cfg(test)]
 mod tests {
+    use std::f64;
+
     use super::*;
 
     #[test]
@@ -36,5 +256,90 @@ mod tests {
         assert_eq!(p.x, 2);
         assert_eq!(p.y, 3);
     }
-}
 
+    #[test]
+    fn test_bounding_box() {
+        let m = mat![1.0, 5.0, 0.0; 3.0, 2.0, -2.0; -1.0, 4.0, 7.0];
+        let b = bounding_box(&m).unwrap();
+        assert_eq!(b.dims(), 3);
+        assert_eq!(b.min, vec![-1.0, 2.0, -2.0]);
+        assert_eq!(b.max, vec![3.0, 5.0, 7.0]);
+        assert!(b.contains(&[0.0, 3.0, 1.0]));
+        assert!(b.contains(&[3.0, 5.0, 7.0]));
+        assert!(!b.contains(&[0.0, 6.0, 1.0]));
+
+        assert!(bounding_box(&Matrix::<f64>::new()).is_none());
+
+        let n = f64::NAN;
+        let m = mat![n, 1.0; 2.0, 5.0; -1.0, n; 0.0, 3.0];
+        let b = bounding_box(&m).unwrap();
+        assert_eq!(b.min, vec![0.0, 3.0]);
+        assert_eq!(b.max, vec![2.0, 5.0]);
+        assert!(bounding_box(&mat![n, 1.0]).is_none());
+    }
+
+    #[test]
+    fn test_convex_hull() {
+        let m = mat![
+            1, 1;
+            0, 0;
+            2, 2;
+            2, 0;
+            1, 0;  // on an edge
+            0, 2;
+            0, 0;  // duplicate
+            1, 3
+        ];
+        assert_eq!(convex_hull(&m), vec![1, 3, 2, 7, 5]);
+
+        assert_eq!(convex_hull(&mat![1, 1; 1, 1]), vec![0]);
+        assert_eq!(convex_hull(&mat![2, 1; 1, 1]), vec![1, 0]);
+        // collinear points
+        assert_eq!(convex_hull(&mat![0, 0; 2, 2; 1, 1]), vec![0, 1]);
+        assert!(convex_hull(&Matrix::<f64>::from_vec(vec![], 0, 2)).is_empty());
+        assert!(convex_hull(&Matrix::<f64>::new()).is_empty());
+
+        let n = f64::NAN;
+        let m = mat![
+            0.0, 0.0;
+            n, 1.0;
+            2.0, 0.0;
+            5.0, n;
+            1.0, 2.0
+        ];
+        assert_eq!(convex_hull(&m), vec![0, 2, 4]);
+        assert!(convex_hull(&mat![n, n; n, 1.0]).is_empty());
+    }
+
+    #[test]
+    fn test_point_in_polygon() {
+        // non-convex polygon in the shape of an U
+        let poly = mat![
+            0.0, 0.0;
+            3.0, 0.0;
+            3.0, 3.0;
+            2.0, 3.0;
+            2.0, 1.0;
+            1.0, 1.0;
+            1.0, 3.0;
+            0.0, 3.0
+        ];
+        assert!(point_in_polygon(&Point2D::new(0.5, 0.5), &poly));
+        assert!(point_in_polygon(&Point2D::new(0.5, 2.5), &poly));
+        assert!(point_in_polygon(&Point2D::new(2.5, 2.5), &poly));
+        assert!(!point_in_polygon(&Point2D::new(1.5, 2.0), &poly));
+        assert!(!point_in_polygon(&Point2D::new(4.0, 1.0), &poly));
+        assert!(!point_in_polygon(&Point2D::new(-1.0, 3.0), &poly));
+        // boundary
+        assert!(point_in_polygon(&Point2D::new(1.5, 0.0), &poly));
+        assert!(point_in_polygon(&Point2D::new(1.5, 1.0), &poly));
+        assert!(point_in_polygon(&Point2D::new(3.0, 3.0), &poly));
+
+        // clockwise order
+        let tri = mat![0, 0; 0, 4; 4, 0];
+        assert!(point_in_polygon(&Point2D::new(1, 1), &tri));
+        assert!(!point_in_polygon(&Point2D::new(3, 3), &tri));
+
+        assert!(!point_in_polygon(&Point2D::new(0, 0), &Matrix::new()));
+    }
+}
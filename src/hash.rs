@@ -1,6 +1,10 @@
 //! Hash functions.
+extern crate rand;
 
-use std::u32;
+use std::{f64, u32, u64};
+use std::hash::Hash;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use self::rand::{Rng, SeedableRng, XorShiftRng};
 
 /// A simple hash functions.
 ///
@@ -18,6 +22,408 @@ pub fn simple_hash(s: &[u8]) -> u32 {
     s.iter().fold::<u64, _>(0, |acc, x| (acc * 31 + (*x as u64)) & m) as u32
 }
 
+/// Computes a 64 bit hash of a byte string.
+///
+/// The bytes are hashed with FNV-1a and the result is passed through the finalizer
+/// of MurmurHash3 so that each bit of the hash depends on all bytes. In contrast
+/// to `simple_hash` collisions are very unlikely, e.g. `"Aa"` and `"BB"` have
+/// different hashes.
+pub fn hash64(s: &[u8]) -> u64 {
+
+    let h = s.iter().fold(0xcbf29ce484222325u64, |acc, &x| (acc ^ x as u64).wrapping_mul(0x100000001b3));
+    mix64(h)
+}
+
+/// Finalizer of MurmurHash3. The function is a bijection on 64 bit values.
+fn mix64(mut x: u64) -> u64 {
+
+    x ^= x >> 33;
+    x = x.wrapping_mul(0xff51afd7ed558ccd);
+    x ^= x >> 33;
+    x = x.wrapping_mul(0xc4ceb9fe1a85ec53);
+    x ^= x >> 33;
+    x
+}
+
+/// Creates the keys of `n` random hash functions `h_k(x) = mix64(x ^ k)`.
+///
+/// Panics if all values of `seed` are zero because the random number generator
+/// cannot be initialized with such a seed.
+fn random_hash_keys(n: usize, seed: [u32; 4]) -> Vec<u64> {
+
+    assert!(seed != [0; 4], "Seed must not be all zeros.");
+    let mut rng = XorShiftRng::from_seed(seed);
+    (0..n).map(|_| rng.gen::<u64>()).collect()
+}
+
+// ----------------------------------------------------------------------------
+
+/// Computes MinHash signatures of token sets.
+///
+/// A signature consists of `n` values. Each value is the minimum of a random
+/// hash function over all tokens of the set. The hash functions are computed
+/// from the 64 bit hash of a token (see `hash64`) and a random key for each
+/// function. The fraction of values in which the signatures of two sets agree
+/// is an estimate of the Jaccard similarity of the sets.
+///
+/// Two different tokens are treated as the same token only if their 64 bit
+/// hashes collide which is very unlikely.
+///
+/// # Example
+///
+/// ```
+/// use rustml::hash::*;
+///
+/// let mh = MinHash::new(128, [1, 2, 3, 4]);
+/// let a = mh.signature(&["the", "quick", "brown", "fox"]);
+/// let b = mh.signature(&["the", "quick", "brown", "dog"]);
+/// let s = jaccard_estimate(&a, &b);
+/// assert!(s > 0.3 && s < 0.9);
+/// ```
+#[derive(Clone, Debug)]
+pub struct MinHash {
+    keys: Vec<u64>
+}
+
+impl MinHash {
+    /// Creates `n` random hash functions. The random number generator is
+    /// initialized with `seed` so that signatures computed by instances
+    /// created with the same seed are comparable.
+    ///
+    /// Panics if all values of `seed` are zero.
+    pub fn new(n: usize, seed: [u32; 4]) -> MinHash {
+
+        MinHash {
+            keys: random_hash_keys(n, seed)
+        }
+    }
+
+    /// Returns the number of values of a signature.
+    pub fn len(&self) -> usize { self.keys.len() }
+
+    /// Returns `true` if the signatures do not contain any values.
+    pub fn is_empty(&self) -> bool { self.keys.is_empty() }
+
+    /// Computes the signature of the given set of tokens.
+    ///
+    /// Duplicate tokens do not change the signature. If the set is empty all
+    /// values of the signature are `u64::MAX`.
+    pub fn signature<T: AsRef<[u8]>>(&self, tokens: &[T]) -> Vec<u64> {
+
+        let mut sig = vec![u64::MAX; self.len()];
+        for t in tokens {
+            let x = hash64(t.as_ref());
+            for (s, k) in sig.iter_mut().zip(self.keys.iter()) {
+                let h = mix64(x ^ k);
+                if h < *s {
+                    *s = h;
+                }
+            }
+        }
+        sig
+    }
+}
+
+/// Estimates the Jaccard similarity of two sets from their MinHash signatures.
+///
+/// Panics if the signatures have different lengths.
+pub fn jaccard_estimate(a: &[u64], b: &[u64]) -> f64 {
+
+    dim_check!(a.len() == b.len(), "Signatures have different lengths.",
+        "jaccard_estimate: a has len {}, b has len {}", a.len(), b.len());
+    if a.is_empty() {
+        return 0.0;
+    }
+    a.iter().zip(b.iter()).filter(|&(x, y)| x == y).count() as f64 / a.len() as f64
+}
+
+/// Finds pairs of near duplicates via locality sensitive hashing (LSH) of
+/// MinHash signatures.
+///
+/// Each signature is split into `bands` bands of equal size. Two signatures
+/// become a candidate pair if they agree in all values of at least one band.
+/// A candidate pair is returned if the estimated Jaccard similarity of the pair is
+/// at least `threshold`. The result contains the indexes `(i, j)` with `i < j`
+/// of the signatures in `signatures` and is sorted in ascending order.
+///
+/// With `r` values per band the probability that a pair with Jaccard similarity `s`
+/// becomes a candidate is `1 - (1 - s^r)^bands`, i.e. more bands find more
+/// candidates at the cost of more comparisons.
+///
+/// Panics if the signatures have different lengths, if `bands` is zero or if
+/// the length of the signatures is not a multiple of `bands`.
+///
+/// # Example
+///
+/// ```
+/// use rustml::hash::*;
+///
+/// let mh = MinHash::new(100, [1, 2, 3, 4]);
+/// let docs = vec![
+///     vec!["a", "b", "c", "d", "e", "f", "g", "h", "i", "j"],
+///     vec!["k", "l", "m", "n", "o", "p", "q", "r", "s", "t"],
+///     vec!["a", "b", "c", "d", "e", "f", "g", "h", "i", "x"],
+/// ];
+/// let sigs = docs.iter().map(|d| mh.signature(d)).collect::<Vec<_>>();
+/// assert_eq!(similarity_join(&sigs, 20, 0.6), vec![(0, 2)]);
+/// ```
+pub fn similarity_join(signatures: &[Vec<u64>], bands: usize, threshold: f64) -> Vec<(usize, usize)> {
+
+    if signatures.is_empty() {
+        return vec![];
+    }
+
+    let n = signatures[0].len();
+    let i = signatures.iter().position(|s| s.len() != n).unwrap_or(0);
+    dim_check!(signatures[i].len() == n, "Signatures have different lengths.",
+        "similarity_join: signatures[0] has len {}, signatures[{}] has len {}", n, i, signatures[i].len());
+    assert!(bands > 0, "Number of bands must be greater than zero.");
+
+    let r = n / bands;
+    assert!(r * bands == n, "Length of signatures must be a multiple of the number of bands.");
+    let mut candidates = HashSet::new();
+
+    for band in 0..bands {
+        let mut buckets: HashMap<&[u64], Vec<usize>> = HashMap::new();
+        for (i, s) in signatures.iter().enumerate() {
+            buckets.entry(&s[band * r..(band + 1) * r]).or_insert(vec![]).push(i);
+        }
+        for idx in buckets.values() {
+            for (k, &i) in idx.iter().enumerate() {
+                for &j in &idx[k + 1..] {
+                    candidates.insert((i, j));
+                }
+            }
+        }
+    }
+
+    let mut pairs = candidates.into_iter()
+        .filter(|&(i, j)| jaccard_estimate(&signatures[i], &signatures[j]) >= threshold)
+        .collect::<Vec<_>>();
+    pairs.sort();
+    pairs
+}
+
+// ----------------------------------------------------------------------------
+
+/// Count-min sketch to estimate the frequencies of items in a stream.
+///
+/// The sketch is a table of counters with `depth` rows and `width` columns. For each
+/// row an item is mapped to one column via a random hash function and the counter
+/// in this column is incremented. The hash functions are computed from the 64 bit
+/// hash of an item (see `hash64`) and a random key for each row. The estimated
+/// count of an item is the minimum over its counters in all rows. The estimate is
+/// never smaller than the true count. With `width = ceil(e / epsilon)` and
+/// `depth = ceil(ln(1 / delta))` it exceeds the true count by more than
+/// `epsilon * N` with a probability of at most `delta` where `N` is the total
+/// count of all items added.
+///
+/// # Example
+///
+/// ```
+/// use rustml::hash::*;
+///
+/// let mut cms = CountMinSketch::with_error(0.001, 0.01, [1, 2, 3, 4]);
+/// for v in "a b a c a b".split(' ') {
+///     cms.add(v);
+/// }
+/// assert_eq!(cms.estimate("a"), 3);
+/// assert_eq!(cms.estimate("b"), 2);
+/// assert_eq!(cms.total(), 6);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CountMinSketch {
+    width: usize,
+    depth: usize,
+    counts: Vec<u64>,
+    total: u64,
+    keys: Vec<u64>
+}
+
+impl CountMinSketch {
+    /// Creates a sketch with `depth` rows and `width` columns. The random number
+    /// generator for the hash functions is initialized with `seed`.
+    ///
+    /// Panics if `width` or `depth` is zero or if all values of `seed` are zero.
+    pub fn new(width: usize, depth: usize, seed: [u32; 4]) -> CountMinSketch {
+
+        assert!(width > 0 && depth > 0, "Width and depth must be greater than zero.");
+        CountMinSketch {
+            width: width,
+            depth: depth,
+            counts: vec![0; width * depth],
+            total: 0,
+            keys: random_hash_keys(depth, seed)
+        }
+    }
+
+    /// Creates a sketch whose estimates exceed the true counts by more than
+    /// `epsilon` times the total count with a probability of at most `delta`.
+    ///
+    /// Panics if `epsilon` or `delta` is not within the interval (0, 1) or if
+    /// all values of `seed` are zero.
+    pub fn with_error(epsilon: f64, delta: f64, seed: [u32; 4]) -> CountMinSketch {
+
+        assert!(epsilon > 0.0 && epsilon < 1.0, "Epsilon must be within (0, 1).");
+        assert!(delta > 0.0 && delta < 1.0, "Delta must be within (0, 1).");
+        let width = (f64::consts::E / epsilon).ceil() as usize;
+        let depth = (1.0 / delta).ln().ceil() as usize;
+        CountMinSketch::new(width, depth, seed)
+    }
+
+    /// Returns the number of columns of the sketch.
+    pub fn width(&self) -> usize { self.width }
+
+    /// Returns the number of rows of the sketch.
+    pub fn depth(&self) -> usize { self.depth }
+
+    /// Returns the total count of all items added to the sketch.
+    pub fn total(&self) -> u64 { self.total }
+
+    /// Increments the count of the item by one.
+    pub fn add<T: AsRef<[u8]>>(&mut self, item: T) {
+        self.add_count(item, 1);
+    }
+
+    /// Increments the count of the item by `count`.
+    pub fn add_count<T: AsRef<[u8]>>(&mut self, item: T, count: u64) {
+
+        let x = hash64(item.as_ref());
+        for row in 0..self.depth {
+            let idx = row * self.width + self.column(row, x);
+            self.counts[idx] += count;
+        }
+        self.total += count;
+    }
+
+    /// Returns the estimated count of the item.
+    pub fn estimate<T: AsRef<[u8]>>(&self, item: T) -> u64 {
+
+        let x = hash64(item.as_ref());
+        (0..self.depth)
+            .map(|row| self.counts[row * self.width + self.column(row, x)])
+            .min()
+            .unwrap()
+    }
+
+    /// Merges the counts of `other` into this sketch.
+    ///
+    /// Panics if both sketches do not have the same dimensions or were not
+    /// created with the same seed.
+    pub fn merge(&mut self, other: &CountMinSketch) {
+
+        safety_dim_check!(self.width == other.width && self.depth == other.depth,
+            "Dimensions of sketches do not match.",
+            "merge: self is {}x{}, other is {}x{}", self.depth, self.width, other.depth, other.width);
+        assert!(self.keys == other.keys, "Sketches were created with different seeds.");
+        for (i, j) in self.counts.iter_mut().zip(other.counts.iter()) {
+            *i += *j;
+        }
+        self.total += other.total;
+    }
+
+    fn column(&self, row: usize, x: u64) -> usize {
+        (mix64(x ^ self.keys[row]) % self.width as u64) as usize
+    }
+}
+
+/// Tracks the `k` most frequent items of a stream.
+///
+/// The counts of the items are estimated with a `CountMinSketch`. For each item
+/// that is added the tracker keeps the item if its estimated count is larger than
+/// the smallest estimated count of the `k` items currently tracked. The counts
+/// of the tracked items are kept in an ordered set so that each item is added
+/// in O(depth + log k).
+///
+/// # Example
+///
+/// ```
+/// use rustml::hash::*;
+///
+/// let mut hh = HeavyHitters::new(2, CountMinSketch::new(1000, 5, [1, 2, 3, 4]));
+/// for v in "a b a c a b d".split(' ') {
+///     hh.add(v);
+/// }
+/// assert_eq!(hh.top(), vec![("a", 3), ("b", 2)]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct HeavyHitters<T> {
+    k: usize,
+    sketch: CountMinSketch,
+    // tracked items, their slots in `items` and their counts when they were added
+    items: Vec<T>,
+    slots: HashMap<T, usize>,
+    counts: Vec<u64>,
+    // (count, slot) of all tracked items, i.e. the first entry is the minimum
+    order: BTreeSet<(u64, usize)>
+}
+
+impl <T: AsRef<[u8]> + Hash + Eq + Clone> HeavyHitters<T> {
+    /// Creates a tracker for the `k` most frequent items which uses
+    /// the given sketch to estimate the counts.
+    pub fn new(k: usize, sketch: CountMinSketch) -> HeavyHitters<T> {
+        HeavyHitters {
+            k: k,
+            sketch: sketch,
+            items: vec![],
+            slots: HashMap::new(),
+            counts: vec![],
+            order: BTreeSet::new()
+        }
+    }
+
+    /// Adds the item to the stream.
+    pub fn add(&mut self, item: T) {
+
+        self.sketch.add(&item);
+        let c = self.sketch.estimate(&item);
+
+        let slot = match self.slots.get(&item) {
+            Some(&slot) => {
+                self.order.remove(&(self.counts[slot], slot));
+                slot
+            }
+            None if self.items.len() < self.k => {
+                self.items.push(item.clone());
+                self.counts.push(c);
+                self.slots.insert(item, self.items.len() - 1);
+                self.items.len() - 1
+            }
+            None => {
+                let (n, slot) = match self.order.iter().next() {
+                    Some(&min) => min,
+                    None => return
+                };
+                if c <= n {
+                    return;
+                }
+                self.order.remove(&(n, slot));
+                self.slots.remove(&self.items[slot]);
+                self.items[slot] = item.clone();
+                self.slots.insert(item, slot);
+                slot
+            }
+        };
+        self.counts[slot] = c;
+        self.order.insert((c, slot));
+    }
+
+    /// Returns the tracked items with their estimated counts sorted in
+    /// descending order of the counts. Items with the same count are sorted
+    /// in ascending order.
+    pub fn top(&self) -> Vec<(T, u64)> where T: Ord {
+
+        let mut v = self.items.iter()
+            .map(|i| (i.clone(), self.sketch.estimate(i)))
+            .collect::<Vec<_>>();
+        v.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        v
+    }
+
+    /// Returns the sketch used to estimate the counts.
+    pub fn sketch(&self) -> &CountMinSketch { &self.sketch }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -27,5 +433,160 @@ mod tests {
         assert_eq!(simple_hash("a".as_bytes()), 97);
         assert_eq!(simple_hash("Joe Miller".as_bytes()), 149190249);
     }
+
+    #[test]
+    fn test_minhash() {
+        let mh = MinHash::new(200, [1, 2, 3, 4]);
+        assert_eq!(mh.len(), 200);
+        assert!(!mh.is_empty());
+
+        let a = mh.signature(&["a", "b", "c", "d"]);
+        assert_eq!(a, mh.signature(&["d", "c", "b", "a", "a"]));
+        assert_eq!(a, MinHash::new(200, [1, 2, 3, 4]).signature(&["a", "b", "c", "d"]));
+        assert_eq!(jaccard_estimate(&a, &a), 1.0);
+
+        // exact Jaccard similarity is 3/5
+        let b = mh.signature(&["a", "b", "c", "e"]);
+        assert!((jaccard_estimate(&a, &b) - 0.6).abs() <= 0.15);
+
+        let c = mh.signature(&["w", "x", "y", "z"]);
+        assert!(jaccard_estimate(&a, &c) <= 0.1);
+
+        let e: Vec<&str> = vec![];
+        assert!(mh.signature(&e).iter().all(|&x| x == u64::MAX));
+    }
+
+    #[test]
+    #[should_panic(expected = "Seed must not be all zeros.")]
+    fn test_minhash_zero_seed_panic() {
+        MinHash::new(10, [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_minhash_simple_hash_collision() {
+        // tokens with the same simple_hash must still be different tokens
+        assert_eq!(simple_hash("Aa".as_bytes()), simple_hash("BB".as_bytes()));
+        assert!(hash64("Aa".as_bytes()) != hash64("BB".as_bytes()));
+
+        let mh = MinHash::new(100, [1, 2, 3, 4]);
+        let a = mh.signature(&["Aa"]);
+        let b = mh.signature(&["BB"]);
+        assert_eq!(jaccard_estimate(&a, &b), 0.0);
+        assert!(similarity_join(&[a, b], 20, 0.5).is_empty());
+    }
+
+    #[test]
+    fn test_similarity_join() {
+        let mh = MinHash::new(100, [1, 2, 3, 4]);
+        let docs = [
+            "the quick brown fox jumps over the lazy dog",
+            "a completely different sentence about rust",
+            "the quick brown fox jumps over the lazy cat",
+            "the quick brown fox jumps over the lazy dog",
+        ];
+        let sigs = docs.iter()
+            .map(|d| mh.signature(&d.split(' ').collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(similarity_join(&sigs, 25, 0.6), vec![(0, 2), (0, 3), (2, 3)]);
+        assert_eq!(similarity_join(&sigs, 25, 1.0), vec![(0, 3)]);
+        assert!(similarity_join(&[], 25, 0.5).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "similarity_join: signatures[0] has len 4, signatures[2] has len 2")]
+    #[cfg(all(feature = "strict_dims", not(all(feature = "unchecked_dims", not(debug_assertions)))))]
+    fn test_similarity_join_panic_strict() {
+        similarity_join(&[vec![1, 2, 3, 4], vec![1, 2, 3, 4], vec![1, 2]], 2, 0.5);
+    }
+
+    #[test]
+    fn test_count_min_sketch() {
+        let mut cms = CountMinSketch::with_error(0.01, 0.01, [1, 2, 3, 4]);
+        assert_eq!(cms.width(), 272);
+        assert_eq!(cms.depth(), 5);
+
+        for i in 0..1000 {
+            cms.add(format!("{}", i % 100));
+        }
+        cms.add_count("x", 42);
+        assert_eq!(cms.total(), 1042);
+
+        for i in 0..100 {
+            let c = cms.estimate(format!("{}", i));
+            assert!((10..=10 + 11).contains(&c));
+        }
+        assert!(cms.estimate("x") >= 42);
+
+        let mut other = CountMinSketch::with_error(0.01, 0.01, [1, 2, 3, 4]);
+        other.add_count("x", 8);
+        cms.merge(&other);
+        assert!(cms.estimate("x") >= 50);
+        assert_eq!(cms.total(), 1050);
+    }
+
+    #[test]
+    fn test_count_min_sketch_simple_hash_collision() {
+        assert_eq!(simple_hash("Aa".as_bytes()), simple_hash("BB".as_bytes()));
+
+        let mut cms = CountMinSketch::new(100000, 8, [1, 2, 3, 4]);
+        cms.add_count("Aa", 1000);
+        assert_eq!(cms.estimate("Aa"), 1000);
+        assert_eq!(cms.estimate("BB"), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Sketches were created with different seeds.")]
+    fn test_count_min_sketch_merge_seed_panic() {
+        let mut a = CountMinSketch::new(100, 4, [1, 2, 3, 4]);
+        let b = CountMinSketch::new(100, 4, [5, 6, 7, 8]);
+        a.merge(&b);
+    }
+
+    #[test]
+    #[should_panic(expected = "merge: self is 4x100, other is 5x100")]
+    #[cfg(feature = "strict_dims")]
+    fn test_count_min_sketch_merge_panic_strict() {
+        let mut a = CountMinSketch::new(100, 4, [1, 2, 3, 4]);
+        let b = CountMinSketch::new(100, 5, [1, 2, 3, 4]);
+        a.merge(&b);
+    }
+
+    #[test]
+    fn test_heavy_hitters() {
+        let mut hh = HeavyHitters::new(3, CountMinSketch::new(500, 4, [1, 2, 3, 4]));
+        for i in 0..1000 {
+            let v = match i % 10 {
+                0..=3 => "a".to_string(),
+                4 | 5 => "b".to_string(),
+                6 => "c".to_string(),
+                _ => format!("{}", i)
+            };
+            hh.add(v);
+        }
+        // the estimates are never smaller than the true counts and exceed them
+        // by at most a few collisions with the 300 other items
+        let top = hh.top();
+        assert_eq!(top.iter().map(|x| x.0.as_str()).collect::<Vec<_>>(), vec!["a", "b", "c"]);
+        for (&(_, n), &e) in top.iter().zip([400, 200, 100].iter()) {
+            assert!(n >= e && n <= e + 5);
+        }
+        assert_eq!(hh.sketch().total(), 1000);
+    }
+
+    #[test]
+    fn test_heavy_hitters_replace() {
+        let mut hh = HeavyHitters::new(1, CountMinSketch::new(500, 4, [1, 2, 3, 4]));
+        hh.add("a");
+        hh.add("b");
+        assert_eq!(hh.top(), vec![("a", 1)]);
+        hh.add("b");
+        hh.add("b");
+        assert_eq!(hh.top(), vec![("b", 3)]);
+
+        let mut hh = HeavyHitters::new(0, CountMinSketch::new(500, 4, [1, 2, 3, 4]));
+        hh.add("a");
+        assert!(hh.top().is_empty());
+    }
 }
 
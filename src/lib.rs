@@ -57,6 +57,17 @@
 //! [here](https://github.com/daniel-e/rustml/tree/master/build).
 //!
 //!
+//! # Dimension checks
+//!
+//! Operations on vectors and matrices panic if the dimensions of the operands
+//! do not match. Two cargo features control these checks:
+//!
+//! * `strict_dims` - the panic message contains the name of the operation and
+//! the shapes of the operands (e.g. `d_gemv: A is 128x64, x has len 63, y has len 128`).
+//! * `unchecked_dims` - checks which are not required for memory safety or for the
+//! consistency of data structures are compiled out in release builds. Checks in
+//! front of calls into BLAS are always performed.
+//!
 //! # Machine Learning Pipelines with Rustml
 //!
 //! The Rustml pipeline is a small and simple framework to build
@@ -131,6 +142,8 @@ pub use datasets::{mixture_builder, normal_builder};
 // ordering is important because the macro mat! is 
 // only available for modules which follow #[macro_use]
 #[macro_use]
+mod dims;
+#[macro_use]
 pub mod matrix;
 
 pub mod blas;
@@ -129,9 +129,9 @@ pub trait IntoMatrix<T> {
 impl <T: Clone> IntoMatrix<T> for Vec<T> {
 
     fn to_matrix(&self, rows: usize) -> Matrix<T> {
-        assert!(self.len() % rows == 0, 
-            "The length of the vector must be divisible by the number of rows."
-        );
+        dim_check!(self.len() % rows == 0,
+            "The length of the vector must be divisible by the number of rows.",
+            "to_matrix: vector has len {}, rows is {}", self.len(), rows);
         Matrix::from_vec(self.clone(), rows, self.len() / rows)
     }
 }
@@ -139,9 +139,9 @@ impl <T: Clone> IntoMatrix<T> for Vec<T> {
 impl <T: Clone> IntoMatrix<T> for [T] {
 
     fn to_matrix(&self, rows: usize) -> Matrix<T> {
-        assert!(self.len() % rows == 0, 
-            "The length of the vector must be divisible by the number of rows."
-        );
+        dim_check!(self.len() % rows == 0,
+            "The length of the vector must be divisible by the number of rows.",
+            "to_matrix: vector has len {}, rows is {}", self.len(), rows);
         Matrix::from_vec(self.to_vec(), rows, self.len() / rows)
     }
 }
@@ -221,13 +221,9 @@ impl <T: Clone + Signed + Float> Similar<T> for Matrix<T> {
 
     fn similar(&self, e: &Self, epsilon: T) -> bool {
 
-        assert!(
-            self.rows() == e.rows() && 
-            self.cols() == e.cols(), 
-            format!("Dimensions of matrices do not match. {}x{}, {}x{}",
-                    self.rows(), self.cols(), e.rows(), e.cols()
-            )
-        );
+        dim_check!(self.rows() == e.rows() && self.cols() == e.cols(),
+            "Dimensions of matrices do not match.",
+            "similar: lhs is {}x{}, rhs is {}x{}", self.rows(), self.cols(), e.rows(), e.cols());
 
         self.iter().zip(e.iter()).all(|(&x, &y)| num::abs(x - y) <= epsilon)
     }
@@ -245,7 +241,8 @@ impl <T: Clone + Signed + Float> Similar<T> for [T] {
 
     fn similar(&self, e: &Self, epsilon: T) -> bool {
 
-        assert!(self.len() == e.len(), "Dimensions of vectors do not match.");
+        dim_check!(self.len() == e.len(), "Dimensions of vectors do not match.",
+            "similar: lhs has len {}, rhs has len {}", self.len(), e.len());
         self.iter().zip(e.iter()).all(|(&x, &y)| num::abs(x - y) <= epsilon)
     }
 }
@@ -381,9 +378,9 @@ impl <T: Clone> Matrix<T> {
     /// ```
     pub fn from_vec(vals: Vec<T>, rows: usize, cols: usize) -> Matrix<T> {
 
-        assert!(rows * cols == vals.len(),
-            "Number of elements in vector must be equal to the number of elements in the matrix."
-        );
+        safety_dim_check!(rows * cols == vals.len(),
+            "Number of elements in vector must be equal to the number of elements in the matrix.",
+            "from_vec: vector has len {}, matrix is {}x{}", vals.len(), rows, cols);
 
         Matrix {
             nrows: rows,
@@ -880,9 +877,8 @@ impl <T: Clone> Matrix<T> {
         if self.rows() == 0 {
             self.ncols = row.len();
         } else {
-            if self.cols() != row.len() {
-                panic!("Invalid dimension.");
-            }
+            safety_dim_check!(self.cols() == row.len(), "Invalid dimension.",
+                "add_row: matrix is {}x{}, row has len {}", self.rows(), self.cols(), row.len());
         }
         self.nrows += 1;
         for i in row {
@@ -929,9 +925,8 @@ impl <T: Clone> Matrix<T> {
             return Matrix::from_vec(v.to_vec(), v.len(), 1);
         }
 
-        assert!(v.len() == self.rows(), 
-            "Length of vector must be equal to the number of rows."
-        );
+        dim_check!(v.len() == self.rows(), "Length of vector must be equal to the number of rows.",
+            "insert_column: matrix is {}x{}, v has len {}", self.rows(), self.cols(), v.len());
 
         let mut m = Matrix::<T>::new();
         for (i, r) in self.row_iter().enumerate() {
@@ -1022,9 +1017,9 @@ impl <T: Clone> Matrix<T> {
     /// elements stored in the matrix.
     pub fn reshape_mut(&mut self, rows: usize, cols: usize) {
 
-        assert!(rows * cols <= self.data.len(),
-            "The new shape must not contain more elements."
-        );
+        safety_dim_check!(rows * cols <= self.data.len(),
+            "The new shape must not contain more elements.",
+            "reshape_mut: matrix is {}x{}, new shape is {}x{}", self.rows(), self.cols(), rows, cols);
 
         if rows * cols < self.nrows * self.ncols {
             self.data.truncate(rows * cols);
@@ -1042,9 +1037,9 @@ impl <T: Clone> Matrix<T> {
     /// elements stored in the matrix.
     pub fn reshape(&self, rows: usize, cols: usize) -> Matrix<T> {
 
-        assert!(rows * cols <= self.data.len(),
-            "The new shape must not contain more elements."
-        );
+        dim_check!(rows * cols <= self.data.len(),
+            "The new shape must not contain more elements.",
+            "reshape: matrix is {}x{}, new shape is {}x{}", self.rows(), self.cols(), rows, cols);
 
         Matrix {
             nrows: rows,
@@ -1234,11 +1229,8 @@ impl Mul for Matrix<f64> {
     /// ```
     fn mul(self, rhs: Matrix<f64>) -> Self::Output {
 
-        assert!(self.cols() == rhs.rows(),
-            format!("Dimensions of matrices do not match. {}x{} * {}x{}",
-                self.rows(), self.cols(), rhs.rows(), rhs.cols()
-            )
-        );
+        safety_dim_check!(self.cols() == rhs.rows(), "Dimensions of matrices do not match.",
+            "mul: lhs is {}x{}, rhs is {}x{}", self.rows(), self.cols(), rhs.rows(), rhs.cols());
 
         let mut c = Matrix::fill(0.0, self.rows(), rhs.cols());
         d_gemm(1.0, &self, &rhs, 0.0, &mut c, false, false);
@@ -1273,11 +1265,8 @@ impl Mul for Matrix<f32> {
     /// ```
     fn mul(self, rhs: Matrix<f32>) -> Self::Output {
 
-        assert!(self.cols() == rhs.rows(),
-            format!("Dimensions of matrices do not match. {}x{} * {}x{}",
-                self.rows(), self.cols(), rhs.rows(), rhs.cols()
-            )
-        );
+        safety_dim_check!(self.cols() == rhs.rows(), "Dimensions of matrices do not match.",
+            "mul: lhs is {}x{}, rhs is {}x{}", self.rows(), self.cols(), rhs.rows(), rhs.cols());
 
         let mut c = Matrix::fill(0.0, self.rows(), rhs.cols());
         s_gemm(1.0, &self, &rhs, 0.0, &mut c, false, false);
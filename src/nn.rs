@@ -233,8 +233,10 @@ impl NeuralNetwork {
         match m.get_mut(layer) {
             None     => { panic!("Layer does not exist."); }
             Some(mx) => {
-                assert!(mx.rows() == params.rows() && 
-                    mx.cols() == params.cols(), "Parameter matrices do not match.");
+                safety_dim_check!(mx.rows() == params.rows() && mx.cols() == params.cols(),
+                    "Parameter matrices do not match.",
+                    "set_params: parameters of layer {} are {}x{}, params is {}x{}",
+                    layer, mx.rows(), mx.cols(), params.rows(), params.cols());
                 *mx = params;
             }
         }
@@ -368,7 +370,8 @@ impl NeuralNetwork {
     fn feedforward(&self, x: &[f64]) -> (Vec<Vec<f64>>, Vec<Vec<f64>>) {
 
         assert!(self.layers.len() >= 2, "At least two layers are required.");
-        assert!(x.len() == self.input_size(), "Dimension of input vector does not match.");
+        dim_check!(x.len() == self.input_size(), "Dimension of input vector does not match.",
+            "feedforward: network has {} inputs, x has len {}", self.input_size(), x.len());
 
         let mut av = vec![x.to_vec()]; // inputs for the next layer (=sigmoid applied to outputs + bias unit)
         let mut zv = vec![x.to_vec()]; // outputs of previous layer without sigmoid
@@ -389,10 +392,14 @@ impl NeuralNetwork {
     fn backprop(&self, output: &[f64], target: &[f64], av_zv: &(Vec<Vec<f64>>, Vec<Vec<f64>>)) -> Vec<Vec<f64>> {
 
         assert!(self.layers.len() >= 2, "At least two layers are required.");
-        assert!(output.len() == self.output_size(), "Dimension of output vector does not match.");
-        assert!(target.len() == self.output_size(), "Dimension of output vector does not match.");
-        assert!(av_zv.0.len() == self.layers(), "Invalid dimension of vectors in av_zv.");
-        assert!(av_zv.1.len() == self.layers(), "Invalid dimension of vectors in av_zv.");
+        dim_check!(output.len() == self.output_size(), "Dimension of output vector does not match.",
+            "backprop: network has {} outputs, output has len {}", self.output_size(), output.len());
+        dim_check!(target.len() == self.output_size(), "Dimension of output vector does not match.",
+            "backprop: network has {} outputs, target has len {}", self.output_size(), target.len());
+        dim_check!(av_zv.0.len() == self.layers(), "Invalid dimension of vectors in av_zv.",
+            "backprop: network has {} layers, av has len {}", self.layers(), av_zv.0.len());
+        dim_check!(av_zv.1.len() == self.layers(), "Invalid dimension of vectors in av_zv.",
+            "backprop: network has {} layers, zv has len {}", self.layers(), av_zv.1.len());
 
         //let ref av = av_zv.0;
         let ref zv = av_zv.1;
@@ -428,9 +435,15 @@ impl NeuralNetwork {
     pub fn derivatives(&self, examples: &Matrix<f64>, targets: &Matrix<f64>) -> Vec<Matrix<f64>> {
 
         assert!(self.layers.len() >= 2, "At least two layers are required.");
-        assert!(examples.rows() == targets.rows(), "Number of examples and labels mismatch.");
-        assert!(examples.cols() == self.input_size(), "Dimension of input vector does not match.");
-        assert!(self.output_size() == targets.cols(), "Dimension of target values mismatch.");
+        dim_check!(examples.rows() == targets.rows(), "Number of examples and labels mismatch.",
+            "derivatives: examples is {}x{}, targets is {}x{}",
+            examples.rows(), examples.cols(), targets.rows(), targets.cols());
+        dim_check!(examples.cols() == self.input_size(), "Dimension of input vector does not match.",
+            "derivatives: network has {} inputs, examples is {}x{}",
+            self.input_size(), examples.rows(), examples.cols());
+        dim_check!(self.output_size() == targets.cols(), "Dimension of target values mismatch.",
+            "derivatives: network has {} outputs, targets is {}x{}",
+            self.output_size(), targets.rows(), targets.cols());
 
         // create accumulator for the deltas
         let mut acc_d = self.params.iter().map(|ref m| Matrix::fill(0.0, m.rows(), m.cols())).collect();
@@ -460,7 +473,9 @@ impl NeuralNetwork {
     /// parameters from the second layer to the third layer and so on.
     pub fn update_params(&mut self, deltas: &[Matrix<f64>]) {
 
-        assert!(self.params.len() == deltas.len(), "Dimensions do not match.");
+        dim_check!(self.params.len() == deltas.len(), "Dimensions do not match.",
+            "update_params: network has {} parameter matrices, deltas has len {}",
+            self.params.len(), deltas.len());
         for i in 0..self.params.len() {
             self.params[i].iadd(&deltas[i]);
         }
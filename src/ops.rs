@@ -464,9 +464,9 @@ impl MatrixVectorMul<f64> for Matrix<f64> {
 
     fn mul_vec_minus_vec(&self, v: &[f64], y: &[f64]) -> Vec<f64> {
 
-        if self.cols() != v.len() || self.rows() != y.len() {
-            panic!("Invalid dimensions.");
-        }
+        safety_dim_check!(self.cols() == v.len() && self.rows() == y.len(), "Invalid dimensions.",
+            "mul_vec_minus_vec: X is {}x{}, v has len {}, y has len {}",
+            self.rows(), self.cols(), v.len(), y.len());
 
         // this will be modified by cblas_dgemv
         let targets = y.to_vec();
@@ -492,15 +492,10 @@ impl MatrixVectorMul<f64> for Matrix<f64> {
 
     fn mul_dgemv(&self, trans: bool, alpha: f64, x: &[f64], beta: f64, y: &[f64]) -> Vec<f64> {
 
-        if !trans {
-            if self.cols() != x.len() || self.rows() != y.len() {
-                panic!("Invalid dimensions.");
-            }
-        } else {
-            if self.rows() != x.len() || self.cols() != y.len() {
-                panic!("Invalid dimensions.");
-            }
-        }
+        let (n, m) = if trans { (self.rows(), self.cols()) } else { (self.cols(), self.rows()) };
+        safety_dim_check!(n == x.len() && m == y.len(), "Invalid dimensions.",
+            "mul_dgemv: X is {}x{}{}, x has len {}, y has len {}",
+            self.rows(), self.cols(), if trans { " (transposed)" } else { "" }, x.len(), y.len());
 
         let transpose = if trans { Transpose::Trans } else { Transpose::NoTrans };
         // this will be modified by cblas_dgemv
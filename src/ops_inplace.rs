@@ -43,9 +43,8 @@ use matrix::Matrix;
 /// ```
 pub fn d_axpy(alpha: f64, x: &[f64], y: &mut [f64]) {
 
-    if x.len() != y.len() {
-        panic!("Dimensions do not match.")
-    }
+    safety_dim_check!(x.len() == y.len(), "Dimensions do not match.",
+        "d_axpy: x has len {}, y has len {}", x.len(), y.len());
 
     unsafe {
         cblas_daxpy(
@@ -102,12 +101,10 @@ pub fn d_gemm(alpha: f64, a: &Matrix<f64>, b: &Matrix<f64>,
     let rowsb = if transb { b.cols() } else { b.rows() };
     let colsb = if transb { b.rows() } else { b.cols() };
 
-    if colsa != rowsb {
-        panic!(
-            format!("Dimensions for d_gemm do not match: {}x{} * {}x{}", 
-            rowsa, colsa, rowsb, colsb
-        ));
-    }
+    safety_dim_check!(colsa == rowsb && rowsa == c.rows() && colsb == c.cols(),
+        "Dimensions for d_gemm do not match.",
+        "d_gemm: op(A) is {}x{}, op(B) is {}x{}, C is {}x{}",
+        rowsa, colsa, rowsb, colsb, c.rows(), c.cols());
 
     let m = c.rows();
     let n = c.cols();
@@ -163,15 +160,10 @@ pub fn d_gemm(alpha: f64, a: &Matrix<f64>, b: &Matrix<f64>,
 ///
 pub fn d_gemv(trans: bool, alpha: f64, a: &Matrix<f64>, x: &[f64], beta: f64, y: &mut [f64]) {
 
-    if !trans {
-        if a.cols() != x.len() || a.rows() != y.len() {
-            panic!("Invalid dimensions.");
-        }
-    } else {
-        if a.rows() != x.len() || a.cols() != y.len() {
-            panic!("Invalid dimensions.");
-        }
-    }
+    let (n, m) = if trans { (a.rows(), a.cols()) } else { (a.cols(), a.rows()) };
+    safety_dim_check!(n == x.len() && m == y.len(), "Invalid dimensions.",
+        "d_gemv: A is {}x{}{}, x has len {}, y has len {}",
+        a.rows(), a.cols(), if trans { " (transposed)" } else { "" }, x.len(), y.len());
 
     let transpose = if trans { Transpose::Trans } else { Transpose::NoTrans };
 
@@ -238,9 +230,8 @@ pub fn d_nrm2(x: &[f64]) -> f64 {
 /// ```
 pub fn s_axpy(alpha: f32, x: &[f32], y: &mut [f32]) {
 
-    if x.len() != y.len() {
-        panic!("Dimensions do not match.")
-    }
+    safety_dim_check!(x.len() == y.len(), "Dimensions do not match.",
+        "s_axpy: x has len {}, y has len {}", x.len(), y.len());
 
     unsafe {
         cblas_saxpy(
@@ -297,9 +288,10 @@ pub fn s_gemm(alpha: f32, a: &Matrix<f32>, b: &Matrix<f32>,
     let rowsb = if transb { b.cols() } else { b.rows() };
     let colsb = if transb { b.rows() } else { b.cols() };
 
-    if colsa != rowsb || rowsa != c.rows() || colsb != c.cols() {
-        panic!("Dimensions do not match.");
-    }
+    safety_dim_check!(colsa == rowsb && rowsa == c.rows() && colsb == c.cols(),
+        "Dimensions do not match.",
+        "s_gemm: op(A) is {}x{}, op(B) is {}x{}, C is {}x{}",
+        rowsa, colsa, rowsb, colsb, c.rows(), c.cols());
 
     let m = c.rows();
     let n = c.cols();
@@ -384,15 +376,10 @@ pub fn s_nrm2(x: &[f32]) -> f32 {
 ///
 pub fn s_gemv(trans: bool, alpha: f32, a: &Matrix<f32>, x: &[f32], beta: f32, y: &mut [f32]) {
 
-    if !trans {
-        if a.cols() != x.len() || a.rows() != y.len() {
-            panic!("Invalid dimensions.");
-        }
-    } else {
-        if a.rows() != x.len() || a.cols() != y.len() {
-            panic!("Invalid dimensions.");
-        }
-    }
+    let (n, m) = if trans { (a.rows(), a.cols()) } else { (a.cols(), a.rows()) };
+    safety_dim_check!(n == x.len() && m == y.len(), "Invalid dimensions.",
+        "s_gemv: A is {}x{}{}, x has len {}, y has len {}",
+        a.rows(), a.cols(), if trans { " (transposed)" } else { "" }, x.len(), y.len());
 
     let transpose = if trans { Transpose::Trans } else { Transpose::NoTrans };
 
@@ -597,7 +584,8 @@ macro_rules! impl_matrix_matrix_ops_inplace {
 
             fn iadd(&mut self, rhs: &Matrix<$x>) { 
 
-                assert!(self.rows() == rhs.rows() && self.cols() == rhs.cols(), "Dimensions mismatch.");
+                dim_check!(self.rows() == rhs.rows() && self.cols() == rhs.cols(), "Dimensions mismatch.",
+                    "iadd: lhs is {}x{}, rhs is {}x{}", self.rows(), self.cols(), rhs.rows(), rhs.cols());
                 for i in 0..self.rows() {
                     self.row_mut(i).unwrap().iadd(&rhs.row(i).unwrap());
                 }/*
@@ -608,7 +596,8 @@ macro_rules! impl_matrix_matrix_ops_inplace {
 
             fn isub(&mut self, rhs: &Matrix<$x>) { 
 
-                assert!(self.rows() == rhs.rows() && self.cols() == rhs.cols(), "Dimensions mismatch.");
+                dim_check!(self.rows() == rhs.rows() && self.cols() == rhs.cols(), "Dimensions mismatch.",
+                    "isub: lhs is {}x{}, rhs is {}x{}", self.rows(), self.cols(), rhs.rows(), rhs.cols());
                 for i in 0..self.rows() {
                     self.row_mut(i).unwrap().isub(&rhs.row(i).unwrap());
                 }/*
@@ -619,7 +608,8 @@ macro_rules! impl_matrix_matrix_ops_inplace {
 
             fn imule(&mut self, rhs: &Matrix<$x>) {
 
-                assert!(self.rows() == rhs.rows() && self.cols() == rhs.cols(), "Dimensions mismatch.");
+                dim_check!(self.rows() == rhs.rows() && self.cols() == rhs.cols(), "Dimensions mismatch.",
+                    "imule: lhs is {}x{}, rhs is {}x{}", self.rows(), self.cols(), rhs.rows(), rhs.cols());
                 for (i, j) in self.iter_mut().zip(rhs.iter()) {
                     *i = *i * j;
                 }
@@ -755,19 +745,22 @@ macro_rules! impl_vector_vector_ops_inplace {
 
             fn iadd(&mut self, rhs: &[$x]) {
 
-                assert!(self.len() == rhs.len(), "Dimensions do not match.");
+                dim_check!(self.len() == rhs.len(), "Dimensions do not match.",
+                    "iadd: lhs has len {}, rhs has len {}", self.len(), rhs.len());
                 $axpy(1.0, rhs, self);
             }
 
             fn isub(&mut self, rhs: &[$x]) {
 
-                assert!(self.len() == rhs.len(), "Dimensions do not match.");
+                dim_check!(self.len() == rhs.len(), "Dimensions do not match.",
+                    "isub: lhs has len {}, rhs has len {}", self.len(), rhs.len());
                 $axpy(-1.0, rhs, self);
             }
 
             fn idiv(&mut self, rhs: &[$x]) {
 
-                assert!(self.len() == rhs.len(), "Dimensions do not match.");
+                dim_check!(self.len() == rhs.len(), "Dimensions do not match.",
+                    "idiv: lhs has len {}, rhs has len {}", self.len(), rhs.len());
                 for (a, b) in self.iter_mut().zip(rhs.iter()) {
                     *a /= *b;
                 }
@@ -775,7 +768,8 @@ macro_rules! impl_vector_vector_ops_inplace {
 
             fn imul(&mut self, rhs: &[$x]) {
 
-                assert!(self.len() == rhs.len(), "Dimensions do not match.");
+                dim_check!(self.len() == rhs.len(), "Dimensions do not match.",
+                    "imul: lhs has len {}, rhs has len {}", self.len(), rhs.len());
                 for (a, b) in self.iter_mut().zip(rhs.iter()) {
                     *a *= *b;
                 }
@@ -1040,6 +1034,58 @@ mod tests {
         assert_eq!(y, [67.0f32, 76.0]);
     }
 
+    #[test]
+    #[should_panic(expected = "Invalid dimensions.")]
+    #[cfg(not(feature = "strict_dims"))]
+    fn test_d_gemv_panic() {
+        let a = mat![1.0, 2.0, 3.0; 4.0, 2.0, 5.0];
+        let mut y = [7.0, 2.0];
+        d_gemv(false, 2.0, &a, &[2.0, 6.0], 3.0, &mut y);
+    }
+
+    #[test]
+    #[should_panic(expected = "d_gemv: A is 2x3, x has len 2, y has len 2")]
+    #[cfg(feature = "strict_dims")]
+    fn test_d_gemv_panic_strict() {
+        let a = mat![1.0, 2.0, 3.0; 4.0, 2.0, 5.0];
+        let mut y = [7.0, 2.0];
+        d_gemv(false, 2.0, &a, &[2.0, 6.0], 3.0, &mut y);
+    }
+
+    #[test]
+    #[should_panic(expected = "idiv: lhs has len 3, rhs has len 2")]
+    #[cfg(all(feature = "strict_dims", not(all(feature = "unchecked_dims", not(debug_assertions)))))]
+    fn test_idiv_panic_strict() {
+        let mut a = vec![1.0, 2.0, 3.0];
+        a.idiv(&[2.0, 4.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "iadd: lhs is 2x2, rhs is 1x2")]
+    #[cfg(all(feature = "strict_dims", not(all(feature = "unchecked_dims", not(debug_assertions)))))]
+    fn test_matrix_iadd_panic_strict() {
+        let mut a = mat![1.0, 2.0; 3.0, 4.0];
+        a.iadd(&mat![1.0, 2.0]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "unchecked_dims", not(debug_assertions)))]
+    fn test_unchecked_dims() {
+        // the check of imul is compiled out, i.e. only the common prefix is multiplied
+        let mut a = vec![1.0, 2.0, 3.0];
+        a.imul(&[2.0, 4.0]);
+        assert_eq!(a, vec![2.0, 8.0, 3.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid dimensions.")]
+    #[cfg(all(feature = "unchecked_dims", not(debug_assertions), not(feature = "strict_dims")))]
+    fn test_unchecked_dims_safety_check() {
+        // checks in front of calls into BLAS are not compiled out
+        let a = mat![1.0, 2.0, 3.0; 4.0, 2.0, 5.0];
+        let mut y = [7.0, 2.0];
+        d_gemv(false, 2.0, &a, &[2.0, 6.0], 3.0, &mut y);
+    }
 
     #[test]
     fn test_d_nrm2() {